@@ -0,0 +1,17 @@
+-- Baseline schema: the priority_level enum and the todo table
+do $$
+begin
+    create type priority_level as enum ('Low', 'Medium', 'High');
+exception
+    when duplicate_object then null;
+end
+$$;
+
+create table if not exists todo (
+    id uuid primary key,
+    task text not null,
+    priority priority_level not null,
+    created_at timestamptz not null,
+    expired_at timestamptz,
+    completed_at timestamptz
+);
@@ -0,0 +1,2 @@
+-- Deferred todos, hidden from the active list until start_at
+alter table todo add column if not exists start_at timestamptz;
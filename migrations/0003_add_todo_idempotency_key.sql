@@ -0,0 +1,2 @@
+-- Optional key making retried creates safe, see Todo::save_idempotent
+alter table todo add column if not exists idempotency_key text unique;
@@ -0,0 +1,7 @@
+-- "blocked by" dependencies between todos, see Todo::add_dependency
+create table if not exists todo_dependencies (
+    todo_id uuid not null references todo (id) on delete cascade,
+    blocked_by uuid not null references todo (id) on delete cascade,
+    primary key (todo_id, blocked_by),
+    check (todo_id <> blocked_by)
+);
@@ -0,0 +1,16 @@
+-- Last modification of a todo, maintained by a trigger, see Todo::get_changed_since
+alter table todo add column if not exists updated_at timestamptz not null default now();
+
+create index if not exists todo_updated_at_idx on todo (updated_at);
+
+create or replace function todo_set_updated_at() returns trigger as $$
+begin
+    new.updated_at = now();
+    return new;
+end
+$$ language plpgsql;
+
+drop trigger if exists todo_set_updated_at on todo;
+create trigger todo_set_updated_at
+    before update on todo
+    for each row execute function todo_set_updated_at();
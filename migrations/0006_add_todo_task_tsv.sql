@@ -0,0 +1,5 @@
+-- Full-text search over the task, see Todo::search_fts
+alter table todo add column if not exists task_tsv tsvector
+    generated always as (to_tsvector('english', task)) stored;
+
+create index if not exists todo_task_tsv_idx on todo using gin (task_tsv);
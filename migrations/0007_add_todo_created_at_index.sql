@@ -0,0 +1,2 @@
+-- Keyset pagination on (created_at, id), see Todo::get_after
+create index if not exists todo_created_at_id_idx on todo (created_at, id);
@@ -0,0 +1,17 @@
+-- Notify listeners of todo_changes on every change, see DBManager::watch_todos.
+-- The payload is the operation and the todo id, like 'INSERT:<uuid>'.
+create or replace function todo_notify_change() returns trigger as $$
+begin
+    if tg_op = 'DELETE' then
+        perform pg_notify('todo_changes', tg_op || ':' || old.id);
+        return old;
+    end if;
+    perform pg_notify('todo_changes', tg_op || ':' || new.id);
+    return new;
+end
+$$ language plpgsql;
+
+drop trigger if exists todo_notify_change on todo;
+create trigger todo_notify_change
+    after insert or update or delete on todo
+    for each row execute function todo_notify_change();
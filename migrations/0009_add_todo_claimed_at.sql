@@ -0,0 +1,5 @@
+-- Work queue claims, see Todo::claim_next and Todo::release
+alter table todo add column if not exists claimed_at timestamptz;
+
+create index if not exists todo_claimable_idx on todo (priority desc, created_at)
+    where completed_at is null and claimed_at is null;
@@ -0,0 +1,51 @@
+-- Change log of the todo table written by a trigger, see Todo::history.
+-- Inserts keep the new row, deletes the old row and updates only the
+-- columns that changed, both before and after.
+create table if not exists todo_audit (
+    id bigserial primary key,
+    todo_id uuid not null,
+    operation text not null,
+    old_values jsonb,
+    new_values jsonb,
+    changed_at timestamptz not null default now()
+);
+
+create index if not exists todo_audit_todo_id_idx on todo_audit (todo_id, changed_at);
+
+create or replace function todo_audit_change() returns trigger as $$
+declare
+    old_row jsonb;
+    new_row jsonb;
+    changed jsonb;
+begin
+    if tg_op = 'INSERT' then
+        new_row = to_jsonb(new) - 'task_tsv' - 'updated_at';
+        insert into todo_audit (todo_id, operation, new_values) values (new.id, tg_op, new_row);
+        return new;
+    elsif tg_op = 'DELETE' then
+        old_row = to_jsonb(old) - 'task_tsv' - 'updated_at';
+        insert into todo_audit (todo_id, operation, old_values) values (old.id, tg_op, old_row);
+        return old;
+    end if;
+
+    old_row = to_jsonb(old) - 'task_tsv' - 'updated_at';
+    new_row = to_jsonb(new) - 'task_tsv' - 'updated_at';
+    select jsonb_object_agg(n.key, n.value) into changed
+        from jsonb_each(new_row) n
+        where n.value is distinct from old_row -> n.key;
+
+    -- saving a todo without any change doesn't need an entry
+    if changed is not null then
+        insert into todo_audit (todo_id, operation, old_values, new_values)
+        select new.id, tg_op, jsonb_object_agg(o.key, o.value), changed
+            from jsonb_each(old_row) o
+            where changed ? o.key;
+    end if;
+    return new;
+end
+$$ language plpgsql;
+
+drop trigger if exists todo_audit_change on todo;
+create trigger todo_audit_change
+    after insert or update or delete on todo
+    for each row execute function todo_audit_change();
@@ -0,0 +1,4 @@
+-- Owner of a todo, see Todo::get_by_owner and Todo::transfer_to
+alter table todo add column if not exists owner_id uuid;
+
+create index if not exists todo_owner_id_idx on todo (owner_id);
@@ -0,0 +1,16 @@
+-- Completed todos copied by the archival job, see Todo::get_completed_unarchived.
+-- Rows are kept when the todo is deleted, hence no foreign key.
+create table if not exists todo_archive (
+    id uuid primary key,
+    task text not null,
+    priority priority_level not null,
+    created_at timestamptz not null,
+    start_at timestamptz,
+    expired_at timestamptz,
+    completed_at timestamptz not null,
+    owner_id uuid,
+    archived_at timestamptz not null default now()
+);
+
+create index if not exists todo_completed_at_idx on todo (completed_at)
+    where completed_at is not null;
@@ -0,0 +1,2 @@
+-- Time-boxing estimate, see Todo::total_estimated_remaining
+alter table todo add column if not exists estimated_minutes integer check (estimated_minutes >= 0);
@@ -0,0 +1,5 @@
+-- Free-form labels, see Todo::get_by_tags
+alter table todo add column if not exists tags text[] not null default '{}';
+
+-- GIN index serving the @> and && array operators
+create index if not exists todo_tags_idx on todo using gin (tags);
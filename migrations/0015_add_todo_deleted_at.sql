@@ -0,0 +1,6 @@
+-- Soft-delete, see Todo::soft_delete_with_reason
+alter table todo add column if not exists deleted_at timestamptz;
+alter table todo add column if not exists deletion_reason text;
+
+-- Todo::get_deleted lists the trash, a small part of the table
+create index if not exists todo_deleted_at_idx on todo (deleted_at) where deleted_at is not null;
@@ -0,0 +1,5 @@
+-- Subtasks, see Todo::reparent_many. Deleting a todo moves its
+-- subtasks to the top level.
+alter table todo add column if not exists parent_id uuid references todo (id) on delete set null;
+
+create index if not exists todo_parent_id_idx on todo (parent_id);